@@ -0,0 +1,199 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::store::{LookupMap, LookupSet};
+use near_sdk::{env, require, AccountId};
+
+/// The bootstrap admin role. Holders of this role may administer every other
+/// role unless a more specific admin role is configured through
+/// [`AccessControl::set_role_admin`].
+pub const DEFAULT_ADMIN_ROLE: &str = "DEFAULT_ADMIN_ROLE";
+
+const ERR_ROLE_PREFIX: &[u8] = b"__acl_role__";
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct RoleData {
+    members: LookupSet<AccountId>,
+    admin_role: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct AccessControl {
+    roles: LookupMap<String, RoleData>,
+}
+
+impl AccessControl {
+    pub fn new() -> Self {
+        let mut acl = Self { roles: LookupMap::new(ERR_ROLE_PREFIX.to_vec()) };
+        acl.init_role(DEFAULT_ADMIN_ROLE);
+        acl.roles
+            .get_mut(DEFAULT_ADMIN_ROLE)
+            .unwrap()
+            .members
+            .insert(env::predecessor_account_id());
+        acl
+    }
+
+    fn init_role(&mut self, role: &str) {
+        if self.roles.contains_key(role) {
+            return;
+        }
+        let mut prefix = ERR_ROLE_PREFIX.to_vec();
+        prefix.extend_from_slice(role.as_bytes());
+        self.roles.insert(
+            role.to_string(),
+            RoleData { members: LookupSet::new(prefix), admin_role: DEFAULT_ADMIN_ROLE.to_string() },
+        );
+    }
+
+    /// Returns the admin role that controls `role`.
+    pub fn get_role_admin(&self, role: &str) -> Option<String> {
+        self.roles.get(role).map(|data| data.admin_role.clone())
+    }
+
+    /// Sets `admin_role` as the admin of `role`. Only callable by a holder of
+    /// the current admin role of `role`.
+    pub fn set_role_admin(&mut self, role: &str, admin_role: &str) {
+        self.assert_admin(role);
+        self.init_role(role);
+        self.init_role(admin_role);
+        self.roles.get_mut(role).unwrap().admin_role = admin_role.to_string();
+    }
+
+    /// Returns `true` if `account_id` holds `role`.
+    pub fn has_role(&self, role: &str, account_id: &AccountId) -> bool {
+        self.roles.get(role).map(|data| data.members.contains(account_id)).unwrap_or(false)
+    }
+
+    /// Grants `role` to `account_id`. Only callable by a holder of the admin
+    /// role of `role`.
+    pub fn grant_role(&mut self, role: &str, account_id: AccountId) {
+        self.assert_admin(role);
+        self.init_role(role);
+        self.roles.get_mut(role).unwrap().members.insert(account_id);
+    }
+
+    /// Revokes `role` from `account_id`. Only callable by a holder of the admin
+    /// role of `role`.
+    pub fn revoke_role(&mut self, role: &str, account_id: &AccountId) {
+        self.assert_admin(role);
+        if let Some(data) = self.roles.get_mut(role) {
+            data.members.remove(account_id);
+        }
+    }
+
+    /// Renounces `role` for the caller. An account may only renounce roles it
+    /// holds itself, so no admin permission is required.
+    pub fn renounce_role(&mut self, role: &str) {
+        let account_id = env::predecessor_account_id();
+        if let Some(data) = self.roles.get_mut(role) {
+            data.members.remove(&account_id);
+        }
+    }
+
+    /// Panics unless the caller holds `role`.
+    pub fn assert_role(&self, role: &str) {
+        require!(
+            self.has_role(role, &env::predecessor_account_id()),
+            "AccessControl: caller is missing role"
+        );
+    }
+
+    fn assert_admin(&self, role: &str) {
+        let admin_role =
+            self.get_role_admin(role).unwrap_or_else(|| DEFAULT_ADMIN_ROLE.to_string());
+        require!(
+            self.has_role(&admin_role, &env::predecessor_account_id()),
+            "AccessControl: caller is missing admin role"
+        );
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    use super::*;
+
+    const MINTER_ROLE: &str = "MINTER_ROLE";
+
+    fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+        let mut builder = VMContextBuilder::new();
+        builder
+            .current_account_id(accounts(0))
+            .signer_account_id(predecessor_account_id.clone())
+            .predecessor_account_id(predecessor_account_id);
+        builder
+    }
+
+    #[test]
+    fn test_new_seeds_default_admin() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let acl = AccessControl::new();
+        assert!(acl.has_role(DEFAULT_ADMIN_ROLE, &accounts(1)));
+        assert!(!acl.has_role(DEFAULT_ADMIN_ROLE, &accounts(2)));
+    }
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut acl = AccessControl::new();
+        acl.grant_role(MINTER_ROLE, accounts(2));
+        assert!(acl.has_role(MINTER_ROLE, &accounts(2)));
+        acl.revoke_role(MINTER_ROLE, &accounts(2));
+        assert!(!acl.has_role(MINTER_ROLE, &accounts(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "AccessControl: caller is missing admin role")]
+    fn test_grant_role_requires_admin() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut acl = AccessControl::new();
+        let context = get_context(accounts(2));
+        testing_env!(context.build());
+        acl.grant_role(MINTER_ROLE, accounts(3));
+    }
+
+    #[test]
+    fn test_renounce_role() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut acl = AccessControl::new();
+        acl.grant_role(MINTER_ROLE, accounts(2));
+        let context = get_context(accounts(2));
+        testing_env!(context.build());
+        acl.renounce_role(MINTER_ROLE);
+        assert!(!acl.has_role(MINTER_ROLE, &accounts(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "AccessControl: caller is missing role")]
+    fn test_assert_role_fail() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let acl = AccessControl::new();
+        acl.assert_role(MINTER_ROLE);
+    }
+
+    // The nested `store::LookupSet` inside `store::LookupMap` only persists
+    // through the flush that dropping the instance triggers, so exercise a full storage
+    // round-trip to prove members survive a reload rather than only living in
+    // the in-memory cache of a single instance.
+    #[test]
+    fn test_role_survives_storage_roundtrip() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut acl = AccessControl::new();
+        acl.grant_role(MINTER_ROLE, accounts(2));
+
+        let serialized = near_sdk::borsh::to_vec(&acl).unwrap();
+        drop(acl);
+        let restored = <AccessControl as BorshDeserialize>::try_from_slice(&serialized).unwrap();
+
+        assert!(restored.has_role(DEFAULT_ADMIN_ROLE, &accounts(1)));
+        assert!(restored.has_role(MINTER_ROLE, &accounts(2)));
+        assert!(!restored.has_role(MINTER_ROLE, &accounts(3)));
+    }
+}
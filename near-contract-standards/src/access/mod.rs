@@ -0,0 +1,3 @@
+pub mod access_control;
+pub mod ownable;
+pub mod pausable;
@@ -0,0 +1,166 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{require, AccountId};
+
+use super::ownable::Ownable;
+
+/// Owner-gated circuit breaker.
+///
+/// `Pausable` owns its [`Ownable`] rather than taking one by reference, so that
+/// renouncement can only happen through [`Pausable::renounce_ownership`]. That
+/// guard refuses to renounce while paused — otherwise a renounced owner (who can
+/// never pass `only_owner` again) would leave the contract permanently paused
+/// with no account able to unpause it.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Pausable {
+    owner: Ownable,
+    pub is_paused: bool,
+}
+
+impl Pausable {
+    pub fn new() -> Self {
+        Self { owner: Ownable::new(), is_paused: false }
+    }
+
+    pub fn owner(&self) -> Option<AccountId> {
+        self.owner.owner()
+    }
+
+    pub fn only_owner(&self) {
+        self.owner.only_owner();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    pub fn pause(&mut self) {
+        self.owner.only_owner();
+        self.is_paused = true;
+    }
+
+    pub fn unpause(&mut self) {
+        self.owner.only_owner();
+        self.is_paused = false;
+    }
+
+    pub fn assert_not_paused(&self) {
+        require!(!self.is_paused, "Pausable: paused");
+    }
+
+    pub fn assert_paused(&self) {
+        require!(self.is_paused, "Pausable: not paused");
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: AccountId) {
+        self.owner.transfer_ownership(new_owner);
+    }
+
+    /// Renounces ownership, refusing to do so while paused. Because `Pausable`
+    /// owns the [`Ownable`] and does not expose it mutably, this is the only way
+    /// to renounce, so the contract can never be left permanently paused with no
+    /// account able to unpause it.
+    pub fn renounce_ownership(&mut self) {
+        self.assert_not_paused();
+        self.owner.renounce_ownership();
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+    use near_sdk::AccountId;
+
+    use super::*;
+
+    fn get_context(predecessor_account_id: AccountId) -> VMContextBuilder {
+        let mut builder = VMContextBuilder::new();
+        builder
+            .current_account_id(accounts(0))
+            .signer_account_id(predecessor_account_id.clone())
+            .predecessor_account_id(predecessor_account_id);
+        builder
+    }
+
+    #[test]
+    fn test_new() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let pausable = Pausable::new();
+        assert!(!pausable.is_paused());
+    }
+
+    #[test]
+    fn test_pause_unpause() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut pausable = Pausable::new();
+        pausable.pause();
+        assert!(pausable.is_paused());
+        pausable.unpause();
+        assert!(!pausable.is_paused());
+    }
+
+    #[test]
+    #[should_panic(expected = "Ownable: caller is not the owner")]
+    fn test_pause_not_owner() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut pausable = Pausable::new();
+        let context = get_context(accounts(2));
+        testing_env!(context.build());
+        pausable.pause();
+    }
+
+    #[test]
+    fn test_assert_not_paused_success() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let pausable = Pausable::new();
+        pausable.assert_not_paused();
+    }
+
+    #[test]
+    #[should_panic(expected = "Pausable: paused")]
+    fn test_assert_not_paused_fail() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut pausable = Pausable::new();
+        pausable.pause();
+        pausable.assert_not_paused();
+    }
+
+    #[test]
+    #[should_panic(expected = "Pausable: not paused")]
+    fn test_assert_paused_fail() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let pausable = Pausable::new();
+        pausable.assert_paused();
+    }
+
+    // A renounced owner must not be able to permanently lock the contract: once
+    // ownership is renounced there is no account that passes `only_owner`, so a
+    // pause left in effect could never be lifted. Renouncing through the
+    // `Pausable` guard must therefore be refused while the contract is paused.
+    #[test]
+    #[should_panic(expected = "Pausable: paused")]
+    fn test_cannot_renounce_while_paused() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut pausable = Pausable::new();
+        pausable.pause();
+        pausable.renounce_ownership();
+    }
+
+    #[test]
+    fn test_renounce_when_not_paused() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut pausable = Pausable::new();
+        pausable.renounce_ownership();
+        assert_eq!(pausable.owner(), None);
+    }
+}
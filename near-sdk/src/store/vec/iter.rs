@@ -4,6 +4,54 @@ use core::{iter::FusedIterator, ops::Range};
 use super::{Vector, ERR_INDEX_OUT_OF_BOUNDS};
 use crate::env;
 
+impl<T> Vector<T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    /// Removes the specified range from the vector in bulk, returning all
+    /// removed elements as an iterator. The remaining elements are compacted so
+    /// the collection stays contiguous.
+    ///
+    /// The storage entries backing the drained indices are freed when the
+    /// returned [`Drain`] is dropped, even if it is abandoned before being fully
+    /// consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of the range is out of bounds.
+    pub fn drain(&mut self, range: Range<u32>) -> Drain<T> {
+        Drain::new(self, range)
+    }
+
+    /// Retains only the elements specified by the predicate, dropping the rest
+    /// and compacting storage. This avoids the quadratic cost of repeated
+    /// `swap_remove` calls and preserves the order of the retained elements.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let mut write = 0u32;
+        for read in 0..len {
+            let keep = {
+                let value = self.get(read).unwrap_or_else(|| env::panic_str(ERR_INDEX_OUT_OF_BOUNDS));
+                f(value)
+            };
+            if keep {
+                if write != read {
+                    let value = self.values.remove(read).unwrap();
+                    self.values.set(write, Some(value));
+                }
+                write += 1;
+            } else {
+                self.values.remove(read);
+            }
+        }
+        self.len = write;
+        self.flush();
+    }
+}
+
 /// An iterator over references to each element in the stored vector.
 #[derive(Debug)]
 pub struct Iter<'a, T>
@@ -156,3 +204,104 @@ where
         Some(self.get_mut(idx).unwrap_or_else(|| env::panic_str(ERR_INDEX_OUT_OF_BOUNDS)))
     }
 }
+
+/// A draining iterator over a range of a [`Vector`].
+///
+/// This is created by [`Vector::drain`]. Elements are removed from storage as
+/// they are yielded, and any remaining drained entries are freed on [`Drop`] so
+/// a partially consumed or abandoned iterator never leaves dangling storage.
+#[derive(Debug)]
+pub struct Drain<'a, T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    /// Underlying vector being drained.
+    vec: &'a mut Vector<T>,
+    /// Remaining indices to yield from the drained range.
+    range: Range<u32>,
+    /// First index of the tail that follows the drained range.
+    tail_start: u32,
+    /// Number of elements in the tail that follows the drained range.
+    tail_len: u32,
+    /// Index at which the tail is rewritten once draining completes.
+    new_len: u32,
+}
+
+impl<'a, T> Drain<'a, T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    pub(super) fn new(vec: &'a mut Vector<T>, range: Range<u32>) -> Self {
+        let len = vec.len();
+        if range.start > range.end || range.end > len {
+            env::panic_str(ERR_INDEX_OUT_OF_BOUNDS);
+        }
+        let tail_len = len - range.end;
+        let new_len = range.start;
+        let tail_start = range.end;
+        // Logically shrink the vector up-front so that a leaked `Drain` leaves a
+        // consistent prefix rather than a half-removed collection.
+        vec.len = new_len;
+        Self { vec, range, tail_start, tail_len, new_len }
+    }
+
+    /// Returns the number of elements left to yield.
+    fn remaining(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.range.next()?;
+        Some(self.vec.values.remove(idx).unwrap_or_else(|| env::panic_str(ERR_INDEX_OUT_OF_BOUNDS)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    fn count(self) -> usize {
+        self.remaining()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> where T: BorshSerialize + BorshDeserialize {}
+impl<'a, T> FusedIterator for Drain<'a, T> where T: BorshSerialize + BorshDeserialize {}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.range.next_back()?;
+        Some(self.vec.values.remove(idx).unwrap_or_else(|| env::panic_str(ERR_INDEX_OUT_OF_BOUNDS)))
+    }
+}
+
+impl<'a, T> Drop for Drain<'a, T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    fn drop(&mut self) {
+        // Free any drained entries that were never yielded.
+        for idx in self.range.clone() {
+            self.vec.values.remove(idx);
+        }
+        // Shift the tail down to fill the gap so storage keys stay contiguous.
+        if self.new_len != self.tail_start {
+            for k in 0..self.tail_len {
+                let value = self.vec.values.remove(self.tail_start + k).unwrap();
+                self.vec.values.set(self.new_len + k, Some(value));
+            }
+        }
+        self.vec.len = self.new_len + self.tail_len;
+        self.vec.flush();
+    }
+}
@@ -0,0 +1,327 @@
+//! Coercion of raw byte/string contract inputs into concrete Rust values.
+//!
+//! This is useful for methods that accept human-friendly string arguments
+//! (dates, numeric strings) pulled from [`env::input`] or string-typed JSON
+//! fields and must validate and normalize them before storage.
+//!
+//! Timestamps are parsed with a small self-contained date routine rather than a
+//! heavy datetime crate: a wasm contract SDK keeps its dependency surface
+//! minimal, and only a fixed-offset Gregorian conversion is needed here.
+//!
+//! [`env::input`]: crate::env::input
+
+use core::str::FromStr;
+
+/// A value produced by [`Conversion::convert`], tagged by its concrete type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedValue {
+    Bytes(Vec<u8>),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    /// A timestamp normalized to NEAR's nanosecond block-time unit, directly
+    /// comparable to [`env::block_timestamp`](crate::env::block_timestamp).
+    Timestamp(u64),
+}
+
+/// Error returned when an input cannot be coerced into the requested type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    /// The input was empty.
+    Empty,
+    /// The input was not valid UTF-8 but the target type requires text.
+    NotUtf8,
+    /// The input could not be parsed into the target type.
+    Invalid(String),
+    /// The conversion name was not recognized by [`Conversion::from_str`].
+    UnknownConversion(String),
+}
+
+/// Describes how a raw input should be coerced into a [`TypedValue`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    /// Keep the bytes as-is.
+    Bytes,
+    /// Parse a signed integer.
+    Integer,
+    /// Parse a floating-point number.
+    Float,
+    /// Parse a boolean.
+    Boolean,
+    /// Parse an RFC3339 timestamp into nanoseconds.
+    Timestamp,
+    /// Parse a timestamp using a custom `strftime`-style format into nanoseconds.
+    TimestampFmt(String),
+}
+
+impl Conversion {
+    /// Coerces `bytes` into a [`TypedValue`] according to this conversion.
+    pub fn convert(&self, bytes: &[u8]) -> Result<TypedValue, ConversionError> {
+        if bytes.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        if let Conversion::Bytes = self {
+            return Ok(TypedValue::Bytes(bytes.to_vec()));
+        }
+
+        let text = core::str::from_utf8(bytes).map_err(|_| ConversionError::NotUtf8)?.trim();
+        if text.is_empty() {
+            return Err(ConversionError::Empty);
+        }
+        let invalid = || ConversionError::Invalid(text.to_string());
+
+        match self {
+            Conversion::Bytes => unreachable!("handled above"),
+            Conversion::Integer => text.parse().map(TypedValue::Integer).map_err(|_| invalid()),
+            Conversion::Float => text.parse().map(TypedValue::Float).map_err(|_| invalid()),
+            Conversion::Boolean => text.parse().map(TypedValue::Boolean).map_err(|_| invalid()),
+            Conversion::Timestamp => {
+                parse_rfc3339(text).and_then(fields_to_nanos).map(TypedValue::Timestamp).ok_or_else(invalid)
+            }
+            Conversion::TimestampFmt(fmt) => parse_with_format(text, fmt)
+                .and_then(fields_to_nanos)
+                .map(TypedValue::Timestamp)
+                .ok_or_else(invalid),
+        }
+    }
+}
+
+/// Broken-down timestamp fields alongside a UTC offset in seconds.
+struct DateTimeFields {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+    offset_secs: i64,
+}
+
+/// Days from the Unix epoch for a proleptic Gregorian date (Howard Hinnant's
+/// `days_from_civil`).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Normalizes broken-down fields to NEAR's nanosecond block-time unit. Returns
+/// `None` if the instant is before the Unix epoch or overflows `u64`.
+fn fields_to_nanos(fields: DateTimeFields) -> Option<u64> {
+    if fields.month < 1 || fields.month > 12 || fields.day < 1 || fields.day > 31 {
+        return None;
+    }
+    if fields.hour > 23 || fields.minute > 59 || fields.second > 59 {
+        return None;
+    }
+    let days = days_from_civil(fields.year, fields.month, fields.day);
+    let secs = days * 86_400
+        + fields.hour as i64 * 3_600
+        + fields.minute as i64 * 60
+        + fields.second as i64
+        - fields.offset_secs;
+    if secs < 0 {
+        return None;
+    }
+    let total = secs as i128 * 1_000_000_000 + fields.nanos as i128;
+    u64::try_from(total).ok()
+}
+
+/// Parses a fractional-seconds string (without the leading `.`) into nanoseconds.
+fn parse_fraction(frac: &str) -> Option<u32> {
+    if frac.is_empty() || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits: String = frac.chars().take(9).collect();
+    format!("{:0<9}", digits).parse().ok()
+}
+
+/// Parses the `+HH:MM` / `-HH:MM` / `Z` offset suffix into seconds.
+fn parse_offset(s: &str) -> Option<i64> {
+    if s == "Z" || s == "z" {
+        return Some(0);
+    }
+    let sign = match s.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let mut parts = s[1..].split(':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(sign * (hours * 3_600 + minutes * 60))
+}
+
+/// Parses an RFC3339 timestamp into broken-down fields.
+fn parse_rfc3339(text: &str) -> Option<DateTimeFields> {
+    let sep = text.find(['T', 't', ' '])?;
+    let (date, rest) = text.split_at(sep);
+    let rest = &rest[1..];
+
+    let mut date_parts = date.split('-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+    if date_parts.next().is_some() {
+        return None;
+    }
+
+    // Separate the offset suffix (`Z`/`z`/`+..`/`-..`) from the time portion.
+    let (time_str, offset_secs) = match rest.rfind(['Z', 'z', '+', '-']) {
+        Some(idx) => (&rest[..idx], parse_offset(&rest[idx..])?),
+        None => (rest, 0),
+    };
+
+    // Split off optional fractional seconds.
+    let (hms, nanos) = match time_str.find('.') {
+        Some(idx) => (&time_str[..idx], parse_fraction(&time_str[idx + 1..])?),
+        None => (time_str, 0),
+    };
+
+    let mut time_parts = hms.split(':');
+    let hour: u32 = time_parts.next()?.parse().ok()?;
+    let minute: u32 = time_parts.next()?.parse().ok()?;
+    let second: u32 = time_parts.next()?.parse().ok()?;
+    if time_parts.next().is_some() {
+        return None;
+    }
+
+    Some(DateTimeFields { year, month, day, hour, minute, second, nanos, offset_secs })
+}
+
+/// Parses a timestamp using a `strftime`-style format. Supports `%Y %m %d %H %M
+/// %S %%`; the resulting instant is interpreted as UTC.
+fn parse_with_format(text: &str, fmt: &str) -> Option<DateTimeFields> {
+    let fmt = fmt.as_bytes();
+    let input = text.as_bytes();
+    let mut fi = 0;
+    let mut ti = 0;
+    let mut fields =
+        DateTimeFields { year: 1970, month: 1, day: 1, hour: 0, minute: 0, second: 0, nanos: 0, offset_secs: 0 };
+
+    while fi < fmt.len() {
+        if fmt[fi] == b'%' {
+            fi += 1;
+            let spec = *fmt.get(fi)?;
+            fi += 1;
+            if spec == b'%' {
+                if *input.get(ti)? != b'%' {
+                    return None;
+                }
+                ti += 1;
+                continue;
+            }
+            let start = ti;
+            while ti < input.len() && input[ti].is_ascii_digit() {
+                ti += 1;
+            }
+            if ti == start {
+                return None;
+            }
+            let value: i64 = core::str::from_utf8(&input[start..ti]).ok()?.parse().ok()?;
+            match spec {
+                b'Y' => fields.year = value,
+                b'm' => fields.month = u32::try_from(value).ok()?,
+                b'd' => fields.day = u32::try_from(value).ok()?,
+                b'H' => fields.hour = u32::try_from(value).ok()?,
+                b'M' => fields.minute = u32::try_from(value).ok()?,
+                b'S' => fields.second = u32::try_from(value).ok()?,
+                _ => return None,
+            }
+        } else {
+            if *input.get(ti)? != fmt[fi] {
+                return None;
+            }
+            fi += 1;
+            ti += 1;
+        }
+    }
+    if ti != input.len() {
+        return None;
+    }
+    Some(fields)
+}
+
+impl FromStr for Conversion {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bytes" => Ok(Conversion::Bytes),
+            "int" | "integer" => Ok(Conversion::Integer),
+            "float" => Ok(Conversion::Float),
+            "bool" | "boolean" => Ok(Conversion::Boolean),
+            "timestamp" => Ok(Conversion::Timestamp),
+            other => Err(ConversionError::UnknownConversion(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("int".parse::<Conversion>().unwrap(), Conversion::Integer);
+        assert_eq!("boolean".parse::<Conversion>().unwrap(), Conversion::Boolean);
+        assert!("nope".parse::<Conversion>().is_err());
+    }
+
+    #[test]
+    fn test_bytes_passthrough() {
+        assert_eq!(Conversion::Bytes.convert(b"\xff\x00"), Ok(TypedValue::Bytes(vec![0xff, 0x00])));
+    }
+
+    #[test]
+    fn test_integer_and_float() {
+        assert_eq!(Conversion::Integer.convert(b"42"), Ok(TypedValue::Integer(42)));
+        assert_eq!(Conversion::Float.convert(b"1.5"), Ok(TypedValue::Float(1.5)));
+    }
+
+    #[test]
+    fn test_empty_is_error() {
+        assert_eq!(Conversion::Integer.convert(b""), Err(ConversionError::Empty));
+    }
+
+    #[test]
+    fn test_non_utf8_rejected_for_typed() {
+        assert_eq!(Conversion::Integer.convert(b"\xff"), Err(ConversionError::NotUtf8));
+    }
+
+    #[test]
+    fn test_timestamp_rfc3339_to_nanos() {
+        let value = Conversion::Timestamp.convert(b"1970-01-01T00:00:01Z").unwrap();
+        assert_eq!(value, TypedValue::Timestamp(1_000_000_000));
+    }
+
+    #[test]
+    fn test_timestamp_rfc3339_with_offset_and_fraction() {
+        // 2021-01-01T00:00:00+01:00 == 2020-12-31T23:00:00Z == 1609455600 s.
+        let value = Conversion::Timestamp.convert(b"2021-01-01T00:00:00.5+01:00").unwrap();
+        assert_eq!(value, TypedValue::Timestamp(1_609_455_600_500_000_000));
+    }
+
+    #[test]
+    fn test_timestamp_before_epoch_is_error() {
+        assert!(matches!(
+            Conversion::Timestamp.convert(b"1969-12-31T23:59:59Z"),
+            Err(ConversionError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_custom_fmt() {
+        let conversion = Conversion::TimestampFmt("%Y-%m-%d %H:%M:%S".to_string());
+        let value = conversion.convert(b"1970-01-01 00:00:01").unwrap();
+        assert_eq!(value, TypedValue::Timestamp(1_000_000_000));
+    }
+}
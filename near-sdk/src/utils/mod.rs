@@ -2,6 +2,7 @@
 
 pub(crate) mod storage_key_impl;
 
+pub mod conversion;
 pub mod openapi;
 
 #[cfg(feature = "unstable")]
@@ -78,6 +79,107 @@ macro_rules! require {
     };
 }
 
+/// Error returned by the non-panicking, [`Result`]-returning guard variants.
+///
+/// Unlike [`require!`], which aborts the whole execution through
+/// [`env::panic_str`], a [`ContractError`] lets intermediate logic short-circuit
+/// gracefully — emitting diagnostic events or cleaning up — before the outermost
+/// method boundary turns it into a single structured panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractError {
+    message: String,
+}
+
+impl ContractError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+
+    /// The human-readable error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Converts this error into the terminal host panic, mirroring the behavior
+    /// of [`require!`]. Call this at the outermost method boundary so host
+    /// behavior is unchanged for callers.
+    pub fn panic(&self) -> ! {
+        env::panic_str(&self.message)
+    }
+}
+
+impl From<&str> for ContractError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for ContractError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+/// Unwraps a guard [`Result`], converting a returned [`ContractError`] into the
+/// terminal host panic. Use this at the outermost method boundary.
+pub fn unwrap_or_panic<T>(result: Result<T, ContractError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => error.panic(),
+    }
+}
+
+/// Helper macro mirroring [`require!`] but returning `Err(ContractError)` from
+/// the enclosing function instead of panicking.
+///
+/// # Examples
+///
+/// ```no_run
+/// use near_sdk::ensure;
+/// use near_sdk::utils::ContractError;
+///
+/// # fn main() -> Result<(), ContractError> {
+/// let a = 2;
+/// ensure!(a > 0);
+/// ensure!("test" != "other", "Some custom error message if false");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr $(,)?) => {
+        if !$cond {
+            return Err($crate::utils::ContractError::new("ensure! assertion failed"));
+        }
+    };
+    ($cond:expr, $message:expr $(,)?) => {
+        if !$cond {
+            return Err($crate::utils::ContractError::from($message));
+        }
+    };
+}
+
+/// Non-panicking variant of [`assert_self`].
+pub fn try_assert_self() -> Result<(), ContractError> {
+    ensure!(env::predecessor_account_id() == env::current_account_id(), "Method is private");
+    Ok(())
+}
+
+/// Non-panicking variant of [`assert_one_yocto`].
+pub fn try_assert_one_yocto() -> Result<(), ContractError> {
+    ensure!(env::attached_deposit() == 1, "Requires attached deposit of exactly 1 yoctoNEAR");
+    Ok(())
+}
+
+/// Non-panicking variant of [`promise_result_as_success`].
+pub fn try_promise_result_as_success() -> Result<Option<Vec<u8>>, ContractError> {
+    ensure!(env::promise_results_count() == 1, "Contract expected a result on the callback");
+    Ok(match env::promise_result(0) {
+        PromiseResult::Successful(result) => Some(result),
+        _ => None,
+    })
+}
+
 /// Assert that predecessor_account_id == current_account_id, meaning contract called itself.
 pub fn assert_self() {
     require!(env::predecessor_account_id() == env::current_account_id(), "Method is private");
@@ -207,7 +309,9 @@ macro_rules! setup_alloc {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::get_logs;
+    use super::*;
+    use crate::test_utils::{accounts, get_logs, VMContextBuilder};
+    use crate::testing_env;
 
     #[test]
     fn test_log_simple() {
@@ -222,4 +326,39 @@ mod tests {
 
         assert_eq!(get_logs(), vec!["hello user_name (25)".to_string()]);
     }
+
+    #[test]
+    fn test_try_assert_one_yocto_recoverable() {
+        let mut builder = VMContextBuilder::new();
+        builder.attached_deposit(0);
+        testing_env!(builder.build());
+        let error = try_assert_one_yocto().unwrap_err();
+        assert_eq!(error.message(), "Requires attached deposit of exactly 1 yoctoNEAR");
+    }
+
+    #[test]
+    fn test_try_assert_one_yocto_ok() {
+        let mut builder = VMContextBuilder::new();
+        builder.attached_deposit(1);
+        testing_env!(builder.build());
+        assert!(try_assert_one_yocto().is_ok());
+    }
+
+    #[test]
+    fn test_try_assert_self_recoverable() {
+        let mut builder = VMContextBuilder::new();
+        builder.current_account_id(accounts(0)).predecessor_account_id(accounts(1));
+        testing_env!(builder.build());
+        let error = try_assert_self().unwrap_err();
+        assert_eq!(error.message(), "Method is private");
+    }
+
+    #[test]
+    #[should_panic(expected = "Method is private")]
+    fn test_unwrap_or_panic_terminal() {
+        let mut builder = VMContextBuilder::new();
+        builder.current_account_id(accounts(0)).predecessor_account_id(accounts(1));
+        testing_env!(builder.build());
+        unwrap_or_panic(try_assert_self());
+    }
 }
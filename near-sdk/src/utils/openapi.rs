@@ -0,0 +1,474 @@
+//! Generation of an [OpenAPI 3.0] document from contract [`Method`] metadata.
+//!
+//! Each view method is emitted as a path with a `get` operation and each call
+//! method as a `post`, letting tooling auto-generate clients and docs from a
+//! contract's method set.
+//!
+//! [OpenAPI 3.0]: https://spec.openapis.org/oas/v3.0.3
+//! [`Method`]: crate::utils::Method
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::utils::Method;
+
+/// Provides the JSON schema for a method's `Input`/`Output` type.
+///
+/// A blanket external schema-derive crate (e.g. `schemars`) is intentionally
+/// avoided to keep the SDK's dependency surface small. The trade-off is that
+/// there is no derive: **contract `Input`/`Output` types must implement
+/// `OpenApiSchema` by hand** to appear in the generated spec. Impls for the
+/// primitive and container types that most arguments are built from are
+/// provided below, so a method whose `Input`/`Output` is one of those (or a
+/// `NO_ARGS`/`NO_RETURN` method) needs no manual work; a custom struct does.
+/// The unit type maps to `null` and covers the return of a `NO_RETURN` method.
+pub trait OpenApiSchema {
+    /// Returns the JSON schema describing this type.
+    fn json_schema() -> Value;
+}
+
+impl OpenApiSchema for () {
+    fn json_schema() -> Value {
+        Value::Null
+    }
+}
+
+/// Implements [`OpenApiSchema`] for a type by emitting a fixed JSON Schema node.
+macro_rules! impl_openapi_schema {
+    ($($ty:ty => $schema:tt),+ $(,)?) => {
+        $(impl OpenApiSchema for $ty {
+            fn json_schema() -> Value {
+                json!($schema)
+            }
+        })+
+    };
+}
+
+impl_openapi_schema! {
+    bool => { "type": "boolean" },
+    i8 => { "type": "integer" },
+    i16 => { "type": "integer" },
+    i32 => { "type": "integer" },
+    i64 => { "type": "integer" },
+    u8 => { "type": "integer", "minimum": 0 },
+    u16 => { "type": "integer", "minimum": 0 },
+    u32 => { "type": "integer", "minimum": 0 },
+    u64 => { "type": "integer", "minimum": 0 },
+    f32 => { "type": "number" },
+    f64 => { "type": "number" },
+    String => { "type": "string" },
+    &str => { "type": "string" },
+}
+
+impl<T: OpenApiSchema> OpenApiSchema for Option<T> {
+    fn json_schema() -> Value {
+        let mut schema = T::json_schema();
+        // An optional value is the inner schema with `nullable` set, per the
+        // OpenAPI 3.0 dialect.
+        if let Value::Object(map) = &mut schema {
+            map.insert("nullable".to_string(), Value::Bool(true));
+        }
+        schema
+    }
+}
+
+impl<T: OpenApiSchema> OpenApiSchema for Vec<T> {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+/// The kind of a NEAR contract method, mapped onto an HTTP verb in the spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(crate = "near_sdk::serde", rename_all = "lowercase")]
+pub enum NearMethod {
+    /// A read-only method, emitted as a `get` operation.
+    View,
+    /// A state-changing method, emitted as a `post` operation.
+    Call,
+}
+
+impl NearMethod {
+    /// The HTTP verb this method maps to in the OpenAPI document.
+    fn http_verb(self) -> &'static str {
+        match self {
+            NearMethod::View => "get",
+            NearMethod::Call => "post",
+        }
+    }
+}
+
+/// A dyn-safe description of a single method, collected from a [`Method`] impl.
+#[derive(Debug, Clone)]
+pub struct MethodSpec {
+    pub name: &'static str,
+    pub near_method: NearMethod,
+    pub description: &'static str,
+    pub response_description: &'static str,
+    pub no_args: bool,
+    pub no_return: bool,
+    pub input_schema: Option<Value>,
+    pub output_schema: Option<Value>,
+}
+
+impl MethodSpec {
+    /// Collects the spec for a [`Method`] whose `Input`/`Output` types provide a
+    /// JSON schema through [`OpenApiSchema`].
+    pub fn of<M>() -> Self
+    where
+        M: Method,
+        M::Input: OpenApiSchema,
+        M::Output: OpenApiSchema,
+    {
+        let input_schema = if M::NO_ARGS { None } else { Some(M::Input::json_schema()) };
+        let output_schema = if M::NO_RETURN { None } else { Some(M::Output::json_schema()) };
+        Self {
+            name: M::NAME,
+            near_method: M::NEAR_METHOD,
+            description: M::DESCRIPTION,
+            response_description: M::RESPONSE_DESCRIPTION,
+            no_args: M::NO_ARGS,
+            no_return: M::NO_RETURN,
+            input_schema,
+            output_schema,
+        }
+    }
+}
+
+/// A serde-serializable OpenAPI 3.0 document.
+#[derive(Debug, Clone, Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OpenApi {
+    pub openapi: &'static str,
+    pub info: Info,
+    pub paths: Map<String, Value>,
+}
+
+/// The `info` section of an OpenAPI document.
+#[derive(Debug, Clone, Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Info {
+    pub title: String,
+    pub version: String,
+}
+
+impl OpenApi {
+    /// Serializes the document to a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Serializes the document to a YAML string.
+    ///
+    /// YAML is emitted directly from the document's JSON representation so that
+    /// no dedicated YAML serializer is pulled into the SDK's dependency surface.
+    pub fn to_yaml(&self) -> String {
+        let value = serde_json::to_value(self).unwrap_or(Value::Null);
+        let mut out = String::new();
+        emit_yaml(&value, 0, &mut out);
+        out
+    }
+}
+
+/// Builds an [`OpenApi`] document from the given method specs.
+pub fn build_document(title: &str, version: &str, methods: &[MethodSpec]) -> OpenApi {
+    let mut paths = Map::new();
+    for method in methods {
+        let mut operation = Map::new();
+        operation.insert("summary".to_string(), json!(method.description));
+        operation.insert("description".to_string(), json!(method.description));
+
+        if !method.no_args {
+            if let Some(schema) = &method.input_schema {
+                match method.near_method {
+                    // Call methods carry their input in a JSON request body.
+                    NearMethod::Call => {
+                        operation.insert(
+                            "requestBody".to_string(),
+                            json!({
+                                "required": true,
+                                "content": { "application/json": { "schema": schema } },
+                            }),
+                        );
+                    }
+                    // View methods map onto `get`, which has no body, so the
+                    // input is expressed as query parameters instead.
+                    NearMethod::View => {
+                        operation.insert(
+                            "parameters".to_string(),
+                            Value::Array(schema_to_query_parameters(schema)),
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut response = Map::new();
+        response.insert("description".to_string(), json!(method.response_description));
+        if !method.no_return {
+            if let Some(schema) = &method.output_schema {
+                response.insert(
+                    "content".to_string(),
+                    json!({ "application/json": { "schema": schema } }),
+                );
+            }
+        }
+
+        let operation = json!({
+            method.near_method.http_verb(): {
+                "summary": operation.get("summary"),
+                "description": operation.get("description"),
+                "parameters": operation.get("parameters"),
+                "requestBody": operation.get("requestBody"),
+                "responses": { "200": Value::Object(response) },
+            }
+        });
+
+        // Strip the explicit nulls left by optional fields above.
+        let operation = prune_nulls(operation);
+        paths.insert(format!("/{}", method.name), operation);
+    }
+
+    OpenApi {
+        openapi: "3.0.3",
+        info: Info { title: title.to_string(), version: version.to_string() },
+        paths,
+    }
+}
+
+/// Expands an object input schema into a list of `get` query parameters, one
+/// per top-level property. Properties listed in the schema's `required` array
+/// are marked `required`. A non-object schema yields no parameters.
+fn schema_to_query_parameters(schema: &Value) -> Vec<Value> {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|items| items.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(name, property_schema)| {
+                    json!({
+                        "name": name,
+                        "in": "query",
+                        "required": required.contains(&name.as_str()),
+                        "schema": property_schema,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Emits `value` as block-style YAML into `out`. `indent` is the nesting depth;
+/// each level adds two leading spaces. Mappings and sequences are written in
+/// block form while scalars are written inline.
+fn emit_yaml(value: &Value, indent: usize, out: &mut String) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, val) in map {
+                push_indent(out, indent);
+                out.push_str(&yaml_string(key));
+                out.push(':');
+                emit_yaml_child(val, indent + 1, out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                push_indent(out, indent);
+                out.push('-');
+                emit_yaml_child(item, indent + 1, out);
+            }
+        }
+        // Empty collections and root scalars are written inline.
+        other => {
+            out.push_str(&yaml_scalar(other));
+            out.push('\n');
+        }
+    }
+}
+
+/// Emits the value sitting after a mapping key or sequence dash: non-empty
+/// collections move onto their own indented block, scalars stay on the line.
+fn emit_yaml_child(value: &Value, indent: usize, out: &mut String) {
+    let is_block = matches!(value, Value::Object(map) if !map.is_empty())
+        || matches!(value, Value::Array(items) if !items.is_empty());
+    match value {
+        _ if is_block => {
+            out.push('\n');
+            emit_yaml(value, indent, out);
+        }
+        other => {
+            out.push(' ');
+            out.push_str(&yaml_scalar(other));
+            out.push('\n');
+        }
+    }
+}
+
+/// Pushes `2 * indent` spaces onto `out`.
+fn push_indent(out: &mut String, indent: usize) {
+    for _ in 0..indent {
+        out.push_str("  ");
+    }
+}
+
+/// Renders a scalar (or an empty collection) as a single-line YAML node.
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => yaml_string(s),
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+    }
+}
+
+/// Renders a string as a YAML scalar, double-quoting it when a plain scalar
+/// would be ambiguous. A plain scalar is only kept when it cannot be reparsed
+/// as another type: the conservative checks below reject anything starting with
+/// a digit or an indicator, any control character, and the boolean/null words
+/// of both the YAML 1.2 core schema and the YAML 1.1 schema that older OpenAPI
+/// tooling still uses.
+fn yaml_string(s: &str) -> String {
+    const RESERVED: &[&str] =
+        &["true", "false", "null", "~", "yes", "no", "on", "off", "y", "n"];
+    let reserved = RESERVED.iter().any(|word| word.eq_ignore_ascii_case(s));
+    let needs_quote = s.is_empty()
+        || reserved
+        || s.trim() != s
+        || s.contains(": ")
+        || s.contains(" #")
+        || s.ends_with(':')
+        || s.chars().any(char::is_control)
+        || s.chars().any(|c| c == '"' || c == '\\')
+        || s.starts_with(|c: char| c.is_ascii_digit() || "-+.?:,[]{}#&*!|>'\"%@`".contains(c));
+    if needs_quote {
+        let escaped = s
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+            .replace('\t', "\\t");
+        format!("\"{}\"", escaped)
+    } else {
+        s.to_string()
+    }
+}
+
+/// Recursively removes object entries whose value is `null`.
+fn prune_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, prune_nulls(v)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(prune_nulls).collect()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn greet_spec() -> MethodSpec {
+        MethodSpec {
+            name: "greet",
+            near_method: NearMethod::View,
+            description: "Returns a greeting.",
+            response_description: "The greeting message.",
+            no_args: true,
+            no_return: false,
+            input_schema: None,
+            output_schema: Some(serde_json::json!({ "type": "string" })),
+        }
+    }
+
+    fn set_greeting_spec() -> MethodSpec {
+        MethodSpec {
+            name: "set_greeting",
+            near_method: NearMethod::Call,
+            description: "Updates the greeting.",
+            response_description: "No return value.",
+            no_args: false,
+            no_return: true,
+            input_schema: Some(serde_json::json!({ "type": "object" })),
+            output_schema: None,
+        }
+    }
+
+    #[test]
+    fn test_view_becomes_get() {
+        let doc = build_document("contract", "0.1.0", &[greet_spec()]);
+        let op = &doc.paths["/greet"];
+        assert!(op.get("get").is_some());
+        assert!(op["get"].get("requestBody").is_none());
+    }
+
+    fn get_balance_spec() -> MethodSpec {
+        MethodSpec {
+            name: "get_balance",
+            near_method: NearMethod::View,
+            description: "Returns the balance of an account.",
+            response_description: "The account balance.",
+            no_args: false,
+            no_return: false,
+            input_schema: Some(serde_json::json!({
+                "type": "object",
+                "required": ["account_id"],
+                "properties": { "account_id": { "type": "string" } },
+            })),
+            output_schema: Some(serde_json::json!({ "type": "string" })),
+        }
+    }
+
+    #[test]
+    fn test_view_with_args_emits_query_parameters() {
+        let doc = build_document("contract", "0.1.0", &[get_balance_spec()]);
+        let op = &doc.paths["/get_balance"];
+        assert!(op["get"].get("requestBody").is_none());
+        let params = op["get"]["parameters"].as_array().unwrap();
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0]["name"], "account_id");
+        assert_eq!(params[0]["in"], "query");
+        assert_eq!(params[0]["required"], true);
+    }
+
+    #[test]
+    fn test_call_becomes_post_with_body() {
+        let doc = build_document("contract", "0.1.0", &[set_greeting_spec()]);
+        let op = &doc.paths["/set_greeting"];
+        assert!(op.get("post").is_some());
+        assert!(op["post"].get("requestBody").is_some());
+    }
+
+    #[test]
+    fn test_serializes() {
+        let doc = build_document("contract", "0.1.0", &[greet_spec(), set_greeting_spec()]);
+        assert!(doc.to_json().contains("\"openapi\""));
+        assert!(doc.to_yaml().contains("openapi"));
+    }
+
+    #[test]
+    fn test_primitive_schemas() {
+        assert_eq!(String::json_schema(), serde_json::json!({ "type": "string" }));
+        assert_eq!(u64::json_schema(), serde_json::json!({ "type": "integer", "minimum": 0 }));
+        assert_eq!(
+            Vec::<String>::json_schema(),
+            serde_json::json!({ "type": "array", "items": { "type": "string" } })
+        );
+        assert_eq!(
+            Option::<bool>::json_schema(),
+            serde_json::json!({ "type": "boolean", "nullable": true })
+        );
+    }
+}